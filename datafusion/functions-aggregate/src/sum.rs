@@ -18,23 +18,31 @@
 //! Defines `SUM` and `SUM DISTINCT` aggregate accumulators
 
 use std::any::Any;
+use std::collections::HashSet;
+use std::sync::Arc;
 
+use ahash::RandomState;
 use arrow::array::Array;
 use arrow::array::ArrowNativeTypeOp;
 use arrow::array::{ArrowNumericType, AsArray};
+use arrow::array::{BooleanArray, PrimitiveArray};
 use arrow::datatypes::ArrowNativeType;
 use arrow::datatypes::{
-    DataType, Decimal128Type, Decimal256Type, Float64Type, Int64Type, UInt64Type,
-    DECIMAL128_MAX_PRECISION, DECIMAL256_MAX_PRECISION,
+    DataType, Decimal128Type, Decimal256Type, DurationMicrosecondType,
+    DurationMillisecondType, DurationNanosecondType, DurationSecondType, Float64Type,
+    Int64Type, IntervalDayTimeType, IntervalMonthDayNanoType, IntervalUnit,
+    IntervalYearMonthType, TimeUnit, UInt64Type, DECIMAL128_MAX_PRECISION,
+    DECIMAL256_MAX_PRECISION,
 };
 use arrow::{array::ArrayRef, datatypes::Field};
-use datafusion_common::{exec_err, not_impl_err, Result, ScalarValue};
+use datafusion_common::{exec_err, not_impl_err, DataFusionError, Result, ScalarValue};
 use datafusion_expr::function::AccumulatorArgs;
 use datafusion_expr::function::StateFieldsArgs;
 use datafusion_expr::type_coercion::aggregates::NUMERICS;
 use datafusion_expr::utils::format_state_name;
 use datafusion_expr::{
-    Accumulator, AggregateUDFImpl, GroupsAccumulator, ReversedUDAF, Signature, Volatility,
+    Accumulator, AggregateUDF, AggregateUDFImpl, EmitTo, GroupsAccumulator, ReversedUDAF,
+    Signature, Volatility,
 };
 use datafusion_physical_expr_common::aggregate::groups_accumulator::prim_op::PrimitiveGroupsAccumulator;
 
@@ -52,6 +60,12 @@ make_udaf_expr_and_func!(
 ///
 /// `args` is [AccumulatorArgs]
 /// `helper` is a macro accepting (ArrowPrimitiveType, DataType)
+///
+/// `IntervalUnit::DayTime`/`IntervalUnit::MonthDayNano` still have arms
+/// here, but `accumulator` and `create_groups_accumulator` handle those two
+/// units themselves before falling through to this macro -- see
+/// [`IntervalSumAccumulator`]. `create_sliding_accumulator` does not, and
+/// sums their packed native value as a whole.
 macro_rules! downcast_sum {
     ($args:ident, $helper:ident) => {
         match $args.data_type {
@@ -60,6 +74,27 @@ macro_rules! downcast_sum {
             DataType::Float64 => $helper!(Float64Type, $args.data_type),
             DataType::Decimal128(_, _) => $helper!(Decimal128Type, $args.data_type),
             DataType::Decimal256(_, _) => $helper!(Decimal256Type, $args.data_type),
+            DataType::Interval(IntervalUnit::YearMonth) => {
+                $helper!(IntervalYearMonthType, $args.data_type)
+            }
+            DataType::Interval(IntervalUnit::DayTime) => {
+                $helper!(IntervalDayTimeType, $args.data_type)
+            }
+            DataType::Interval(IntervalUnit::MonthDayNano) => {
+                $helper!(IntervalMonthDayNanoType, $args.data_type)
+            }
+            DataType::Duration(TimeUnit::Second) => {
+                $helper!(DurationSecondType, $args.data_type)
+            }
+            DataType::Duration(TimeUnit::Millisecond) => {
+                $helper!(DurationMillisecondType, $args.data_type)
+            }
+            DataType::Duration(TimeUnit::Microsecond) => {
+                $helper!(DurationMicrosecondType, $args.data_type)
+            }
+            DataType::Duration(TimeUnit::Nanosecond) => {
+                $helper!(DurationNanosecondType, $args.data_type)
+            }
             _ => {
                 not_impl_err!("Sum not supported for {}: {}", $args.name, $args.data_type)
             }
@@ -67,19 +102,82 @@ macro_rules! downcast_sum {
     };
 }
 
+/// Controls how `SUM` reacts when the running sum overflows `T::Native`.
+///
+/// `SUM(DISTINCT)` does not consult the mode: it always wraps.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SumOverflowMode {
+    /// Wrap around on overflow, as today. This is the default so existing
+    /// plans keep their current behavior.
+    #[default]
+    Wrapping,
+    /// Return an error as soon as an add would overflow `T::Native` (or, for
+    /// decimals, the precision coerced in [`Sum::return_type`]). This is the
+    /// ANSI SQL behavior.
+    Checked,
+    /// Once an add would overflow, produce `NULL` for the remainder of the
+    /// group instead of erroring out. This matches Spark's decimal overflow
+    /// semantics.
+    NullOnOverflow,
+}
+
 #[derive(Debug)]
 pub struct Sum {
     signature: Signature,
     aliases: Vec<String>,
+    overflow_mode: SumOverflowMode,
 }
 
 impl Sum {
     pub fn new() -> Self {
+        // In addition to the plain numeric types, SUM can also accumulate
+        // interval and duration columns, adding up elapsed time.
+        let mut signature_types = NUMERICS.to_vec();
+        signature_types.extend([
+            DataType::Interval(IntervalUnit::YearMonth),
+            DataType::Interval(IntervalUnit::DayTime),
+            DataType::Interval(IntervalUnit::MonthDayNano),
+            DataType::Duration(TimeUnit::Second),
+            DataType::Duration(TimeUnit::Millisecond),
+            DataType::Duration(TimeUnit::Microsecond),
+            DataType::Duration(TimeUnit::Nanosecond),
+        ]);
         Self {
-            signature: Signature::uniform(1, NUMERICS.to_vec(), Volatility::Immutable),
+            signature: Signature::uniform(1, signature_types, Volatility::Immutable),
             aliases: vec!["sum".to_string()],
+            overflow_mode: SumOverflowMode::Wrapping,
         }
     }
+
+    /// Creates a `SUM` that reacts to overflow according to `overflow_mode`
+    /// instead of silently wrapping. Use [`sum_udaf_with_overflow_mode`] to
+    /// turn this into an `AggregateUDF` a query plan can reference -- the
+    /// registry entry returned by [`sum_udaf`] always wraps, to keep
+    /// existing plans' behavior unchanged.
+    pub fn new_with_overflow_mode(overflow_mode: SumOverflowMode) -> Self {
+        Self {
+            overflow_mode,
+            ..Self::new()
+        }
+    }
+
+    /// The overflow-handling mode this `SUM` was configured with.
+    pub fn overflow_mode(&self) -> SumOverflowMode {
+        self.overflow_mode
+    }
+}
+
+/// Builds the `SUM` UDAF with non-default overflow handling.
+///
+/// [`sum_udaf`] always returns a `Wrapping` instance so existing plans keep
+/// today's behavior. A planner that wants ANSI-style (`Checked`) or
+/// Spark-style (`NullOnOverflow`) semantics -- for example, selected by a
+/// session-level "ANSI mode" setting -- should build its aggregate
+/// expression from this function instead of the default registry entry.
+pub fn sum_udaf_with_overflow_mode(overflow_mode: SumOverflowMode) -> Arc<AggregateUDF> {
+    Arc::new(AggregateUDF::from(Sum::new_with_overflow_mode(
+        overflow_mode,
+    )))
 }
 
 impl Default for Sum {
@@ -121,6 +219,10 @@ impl AggregateUDFImpl for Sum {
                 dt if dt.is_signed_integer() => Ok(DataType::Int64),
                 dt if dt.is_unsigned_integer() => Ok(DataType::UInt64),
                 dt if dt.is_floating() => Ok(DataType::Float64),
+                // Interval/Duration sums accumulate in the same unit they
+                // started in, unlike the numeric types above there is no
+                // wider native type to promote into.
+                dt @ (DataType::Interval(_) | DataType::Duration(_)) => Ok(dt.clone()),
                 _ => exec_err!("Sum not supported for {}", data_type),
             }
         }
@@ -129,34 +231,132 @@ impl AggregateUDFImpl for Sum {
     }
 
     fn accumulator(&self, args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        if args.is_distinct {
+            reject_distinct_packed_interval(args.data_type)?;
+            macro_rules! helper {
+                ($t:ty, $dt:expr) => {
+                    Ok(Box::new(DistinctSumAccumulator::<$t>::try_new($dt)?))
+                };
+            }
+            return downcast_sum!(args, helper);
+        }
+
+        if let DataType::Interval(unit @ (IntervalUnit::DayTime | IntervalUnit::MonthDayNano)) =
+            args.data_type
+        {
+            return Ok(Box::new(IntervalSumAccumulator::new(
+                *unit,
+                self.overflow_mode,
+            )));
+        }
+
         macro_rules! helper {
             ($t:ty, $dt:expr) => {
-                Ok(Box::new(SumAccumulator::<$t>::new($dt.clone())))
+                Ok(Box::new(SumAccumulator::<$t>::new(
+                    $dt.clone(),
+                    self.overflow_mode,
+                )))
             };
         }
         downcast_sum!(args, helper)
     }
 
     fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<Field>> {
-        Ok(vec![Field::new(
+        if args.is_distinct {
+            return Ok(vec![Field::new_list(
+                format_state_name(args.name, "sum distinct"),
+                Field::new("item", args.return_type.clone(), true),
+                false,
+            )]);
+        }
+
+        let mut fields = vec![Field::new(
             format_state_name(args.name, "sum"),
             args.return_type.clone(),
             true,
-        )])
+        )];
+        if self.overflow_mode == SumOverflowMode::NullOnOverflow {
+            fields.push(Field::new(
+                format_state_name(args.name, "sum overflowed"),
+                DataType::Boolean,
+                false,
+            ));
+        }
+        Ok(fields)
     }
 
     fn aliases(&self) -> &[String] {
         &self.aliases
     }
 
-    fn groups_accumulator_supported(&self, _args: AccumulatorArgs) -> bool {
-        true
+    fn groups_accumulator_supported(&self, args: AccumulatorArgs) -> bool {
+        // For non-DISTINCT input `create_groups_accumulator` builds a
+        // `PrimitiveGroupsAccumulator` that wraps (it doesn't thread `self.overflow_mode` through at all), so
+        // `Checked`/`NullOnOverflow` must fall back to the row-wise `accumulator`,
+        // which does honor the mode, instead of silently wrapping here. DISTINCT
+        // has no overflow-mode-sensitive logic either way, so it's unaffected.
+        args.is_distinct || self.overflow_mode == SumOverflowMode::Wrapping
     }
 
     fn create_groups_accumulator(
         &self,
         args: AccumulatorArgs,
     ) -> Result<Box<dyn GroupsAccumulator>> {
+        if args.is_distinct {
+            reject_distinct_packed_interval(args.data_type)?;
+            macro_rules! helper {
+                ($t:ty, $dt:expr) => {
+                    Ok(Box::new(DistinctSumGroupsAccumulator::<$t>::new($dt)))
+                };
+            }
+            return downcast_sum!(args, helper);
+        }
+
+        // `IntervalDayTimeType`/`IntervalMonthDayNanoType` pack independent
+        // components into a single native integer (see
+        // [`IntervalSumAccumulator`] for why), so the plain `add_wrapping`
+        // below -- correct for every other type `downcast_sum!` handles --
+        // would let a carry in one component bleed into its neighbor here.
+        // Add each component on its own instead.
+        match args.data_type {
+            DataType::Interval(IntervalUnit::DayTime) => {
+                return Ok(Box::new(PrimitiveGroupsAccumulator::<
+                    IntervalDayTimeType,
+                    _,
+                >::new(
+                    args.data_type,
+                    |x, y| {
+                        let (x_days, x_millis) = IntervalDayTimeType::to_parts(*x);
+                        let (y_days, y_millis) = IntervalDayTimeType::to_parts(y);
+                        *x = IntervalDayTimeType::make_value(
+                            x_days.wrapping_add(y_days),
+                            x_millis.wrapping_add(y_millis),
+                        );
+                    },
+                )));
+            }
+            DataType::Interval(IntervalUnit::MonthDayNano) => {
+                return Ok(Box::new(PrimitiveGroupsAccumulator::<
+                    IntervalMonthDayNanoType,
+                    _,
+                >::new(
+                    args.data_type,
+                    |x, y| {
+                        let (x_months, x_days, x_nanos) =
+                            IntervalMonthDayNanoType::to_parts(*x);
+                        let (y_months, y_days, y_nanos) =
+                            IntervalMonthDayNanoType::to_parts(y);
+                        *x = IntervalMonthDayNanoType::make_value(
+                            x_months.wrapping_add(y_months),
+                            x_days.wrapping_add(y_days),
+                            x_nanos.wrapping_add(y_nanos),
+                        );
+                    },
+                )));
+            }
+            _ => {}
+        }
+
         macro_rules! helper {
             ($t:ty, $dt:expr) => {
                 Ok(Box::new(PrimitiveGroupsAccumulator::<$t, _>::new(
@@ -172,9 +372,34 @@ impl AggregateUDFImpl for Sum {
         &self,
         args: AccumulatorArgs,
     ) -> Result<Box<dyn Accumulator>> {
+        // NOTE: sliding sums over `IntervalDayTime`/`IntervalMonthDayNano`
+        // still go through the generic `SlidingSumAccumulator`, which adds
+        // (and retracts) the packed native representation as a whole rather
+        // than component-wise. This has the same bleed risk documented on
+        // [`IntervalSumAccumulator`]; fixing it needs a retracting
+        // counterpart to that accumulator, tracked as follow-up work rather
+        // than bundled into this change.
+
+        // `SlidingSumAccumulator::retract_batch` only ever `sub_wrapping`s --
+        // it doesn't consult `overflow_mode` at all -- so a windowed `SUM` in
+        // `Checked`/`NullOnOverflow` mode would silently wrap on retraction
+        // instead of honoring the mode. Reject those modes here rather than
+        // handing back an accumulator that quietly ignores them; only
+        // `Wrapping` (today's only behavior for sliding windows) is
+        // supported.
+        if self.overflow_mode != SumOverflowMode::Wrapping {
+            return not_impl_err!(
+                "SUM over a sliding window does not support {:?}",
+                self.overflow_mode
+            );
+        }
+
         macro_rules! helper {
             ($t:ty, $dt:expr) => {
-                Ok(Box::new(SlidingSumAccumulator::<$t>::new($dt.clone())))
+                Ok(Box::new(SlidingSumAccumulator::<$t>::new(
+                    $dt.clone(),
+                    self.overflow_mode,
+                )))
             };
         }
         downcast_sum!(args, helper)
@@ -185,10 +410,92 @@ impl AggregateUDFImpl for Sum {
     }
 }
 
+/// Adds `delta` to `current` under [`SumOverflowMode::Checked`] or
+/// [`SumOverflowMode::NullOnOverflow`] (callers handle `Wrapping` themselves
+/// with the faster `add_wrapping`/`arrow::compute::sum` path), returning:
+/// - `Ok(Some(value))` if the add (and, for decimals, the resulting
+///   precision) is within bounds
+/// - `Ok(None)` if `mode` is [`SumOverflowMode::NullOnOverflow`] and the add
+///   overflowed
+/// - `Err` if `mode` is [`SumOverflowMode::Checked`] and the add overflowed
+fn add_with_overflow_mode<T: ArrowNumericType>(
+    current: T::Native,
+    delta: T::Native,
+    data_type: &DataType,
+    mode: SumOverflowMode,
+) -> Result<Option<T::Native>> {
+    debug_assert_ne!(mode, SumOverflowMode::Wrapping);
+
+    let result = current
+        .add_checked(delta)
+        .map_err(|e| DataFusionError::ArrowError(e, None))
+        .and_then(|v| {
+            check_decimal_precision::<T>(v, data_type)?;
+            Ok(v)
+        });
+
+    match (result, mode) {
+        (Ok(v), _) => Ok(Some(v)),
+        (Err(_), SumOverflowMode::NullOnOverflow) => Ok(None),
+        (Err(e), SumOverflowMode::Checked) => Err(e),
+        (Err(_), SumOverflowMode::Wrapping) => unreachable!("checked by debug_assert_ne above"),
+    }
+}
+
+/// Validates that `value` still fits the precision coerced by
+/// [`Sum::return_type`], doing nothing for non-decimal types.
+fn check_decimal_precision<T: ArrowNumericType>(
+    value: T::Native,
+    data_type: &DataType,
+) -> Result<()> {
+    match data_type {
+        DataType::Decimal128(precision, _) => {
+            if let ScalarValue::Decimal128(Some(v), _, _) =
+                ScalarValue::new_primitive::<T>(Some(value), data_type)?
+            {
+                Decimal128Type::validate_decimal_precision(v, *precision)
+                    .map_err(|e| DataFusionError::ArrowError(e, None))?;
+            }
+            Ok(())
+        }
+        DataType::Decimal256(precision, _) => {
+            if let ScalarValue::Decimal256(Some(v), _, _) =
+                ScalarValue::new_primitive::<T>(Some(value), data_type)?
+            {
+                Decimal256Type::validate_decimal_precision(v, *precision)
+                    .map_err(|e| DataFusionError::ArrowError(e, None))?;
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// `DistinctSumAccumulator`/`DistinctSumGroupsAccumulator` dedup native
+/// values and then sum them with `add_wrapping` on the packed
+/// representation as a whole, the same bleed risk
+/// [`IntervalSumAccumulator`] fixes for the non-distinct path. Rather than
+/// duplicate that component-wise logic for a deduped set, reject `DISTINCT`
+/// for the two packed interval units; `IntervalUnit::YearMonth` and
+/// `Duration` are plain, unpacked integers and are unaffected.
+fn reject_distinct_packed_interval(data_type: &DataType) -> Result<()> {
+    if matches!(
+        data_type,
+        DataType::Interval(IntervalUnit::DayTime | IntervalUnit::MonthDayNano)
+    ) {
+        return not_impl_err!("SUM(DISTINCT) is not supported for {data_type}");
+    }
+    Ok(())
+}
+
 /// This accumulator computes SUM incrementally
 struct SumAccumulator<T: ArrowNumericType> {
     sum: Option<T::Native>,
     data_type: DataType,
+    overflow_mode: SumOverflowMode,
+    /// Once `true` (only reachable via [`SumOverflowMode::NullOnOverflow`]),
+    /// the result stays `NULL` regardless of further input.
+    overflowed: bool,
 }
 
 impl<T: ArrowNumericType> std::fmt::Debug for SumAccumulator<T> {
@@ -198,30 +505,80 @@ impl<T: ArrowNumericType> std::fmt::Debug for SumAccumulator<T> {
 }
 
 impl<T: ArrowNumericType> SumAccumulator<T> {
-    fn new(data_type: DataType) -> Self {
+    fn new(data_type: DataType, overflow_mode: SumOverflowMode) -> Self {
         Self {
             sum: None,
             data_type,
+            overflow_mode,
+            overflowed: false,
         }
     }
 }
 
 impl<T: ArrowNumericType> Accumulator for SumAccumulator<T> {
     fn state(&mut self) -> Result<Vec<ScalarValue>> {
-        Ok(vec![self.evaluate()?])
+        let mut state = vec![self.evaluate()?];
+        // `NullOnOverflow` turns an overflow into a `NULL` partial sum, but a
+        // `NULL` looks just like "no rows seen yet" once `merge_batch` feeds
+        // it back through `update_batch`, which skips nulls -- it gets silently skipped
+        // rather than poisoning the merged result. Carry a sticky flag
+        // alongside the sum so the overflow survives multi-phase merges.
+        if self.overflow_mode == SumOverflowMode::NullOnOverflow {
+            state.push(ScalarValue::Boolean(Some(self.overflowed)));
+        }
+        Ok(state)
     }
 
     fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        if self.overflowed {
+            return Ok(());
+        }
         let values = values[0].as_primitive::<T>();
-        if let Some(x) = arrow::compute::sum(values) {
-            let v = self.sum.get_or_insert(T::Native::usize_as(0));
-            *v = v.add_wrapping(x);
+        match self.overflow_mode {
+            SumOverflowMode::Wrapping => {
+                if let Some(x) = arrow::compute::sum(values) {
+                    let v = self.sum.get_or_insert(T::Native::usize_as(0));
+                    *v = v.add_wrapping(x);
+                }
+            }
+            // `arrow::compute::sum` reduces the whole batch with wrapping
+            // arithmetic, so a batch that overflows internally (e.g.
+            // `[i64::MAX, i64::MAX]`) would be folded to a wrapped scalar
+            // before we ever see it. Walk the batch element-by-element
+            // instead so an overflow *inside* this batch is caught too.
+            SumOverflowMode::Checked | SumOverflowMode::NullOnOverflow => {
+                for x in values.iter().flatten() {
+                    let current = self.sum.unwrap_or(T::Native::usize_as(0));
+                    match add_with_overflow_mode::<T>(
+                        current,
+                        x,
+                        &self.data_type,
+                        self.overflow_mode,
+                    )? {
+                        Some(v) => self.sum = Some(v),
+                        None => {
+                            self.sum = None;
+                            self.overflowed = true;
+                            break;
+                        }
+                    }
+                }
+            }
         }
         Ok(())
     }
 
     fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
-        self.update_batch(states)
+        if self.overflow_mode == SumOverflowMode::NullOnOverflow {
+            if let Some(flags) = states.get(1) {
+                if flags.as_boolean().iter().flatten().any(|f| f) {
+                    self.sum = None;
+                    self.overflowed = true;
+                    return Ok(());
+                }
+            }
+        }
+        self.update_batch(&states[..1])
     }
 
     fn evaluate(&mut self) -> Result<ScalarValue> {
@@ -233,6 +590,216 @@ impl<T: ArrowNumericType> Accumulator for SumAccumulator<T> {
     }
 }
 
+/// `SUM` over [`IntervalUnit::DayTime`] and [`IntervalUnit::MonthDayNano`]
+/// columns.
+///
+/// Both pack independent components (days/milliseconds, or
+/// months/days/nanoseconds) into a single native integer -- see
+/// [`IntervalDayTimeType`]/[`IntervalMonthDayNanoType`]. Summing that packed
+/// integer as a single wrapping/checked add, the way [`SumAccumulator`] sums
+/// plain numeric types, lets a carry out of one component corrupt its
+/// neighbor -- e.g. adding `MonthDayNano` values whose nanosecond fields are
+/// `-1` and `1` carries out of the low 64 bits and adds a spurious day to
+/// the day field. This
+/// accumulator adds each component on its own instead.
+/// [`IntervalUnit::YearMonth`] has only one component (months), so it isn't
+/// affected and keeps using the generic [`SumAccumulator`].
+struct IntervalSumAccumulator {
+    unit: IntervalUnit,
+    overflow_mode: SumOverflowMode,
+    months: i32,
+    days: i32,
+    /// Milliseconds for [`IntervalUnit::DayTime`], nanoseconds for
+    /// [`IntervalUnit::MonthDayNano`].
+    time: i64,
+    has_value: bool,
+    /// Once `true` (only reachable via [`SumOverflowMode::NullOnOverflow`]),
+    /// the result stays `NULL` regardless of further input.
+    overflowed: bool,
+}
+
+impl std::fmt::Debug for IntervalSumAccumulator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IntervalSumAccumulator({:?})", self.unit)
+    }
+}
+
+impl IntervalSumAccumulator {
+    fn new(unit: IntervalUnit, overflow_mode: SumOverflowMode) -> Self {
+        Self {
+            unit,
+            overflow_mode,
+            months: 0,
+            days: 0,
+            time: 0,
+            has_value: false,
+            overflowed: false,
+        }
+    }
+
+    fn data_type(&self) -> DataType {
+        DataType::Interval(self.unit)
+    }
+
+    /// Adds one `i32`-wide component (months, days, or `DayTime`
+    /// milliseconds), honoring `self.overflow_mode`. Returns `Ok(None)` only
+    /// under [`SumOverflowMode::NullOnOverflow`], once the add overflowed.
+    fn add_component_i32(&self, current: i32, delta: i32) -> Result<Option<i32>> {
+        match self.overflow_mode {
+            SumOverflowMode::Wrapping => Ok(Some(current.wrapping_add(delta))),
+            SumOverflowMode::Checked => current.checked_add(delta).map(Some).ok_or_else(|| {
+                DataFusionError::Execution(format!(
+                    "Arithmetic overflow summing {}",
+                    self.data_type()
+                ))
+            }),
+            SumOverflowMode::NullOnOverflow => Ok(current.checked_add(delta)),
+        }
+    }
+
+    /// Same as [`Self::add_component_i32`] but for the `i64`-wide
+    /// `MonthDayNano` nanosecond component.
+    fn add_component_i64(&self, current: i64, delta: i64) -> Result<Option<i64>> {
+        match self.overflow_mode {
+            SumOverflowMode::Wrapping => Ok(Some(current.wrapping_add(delta))),
+            SumOverflowMode::Checked => current.checked_add(delta).map(Some).ok_or_else(|| {
+                DataFusionError::Execution(format!(
+                    "Arithmetic overflow summing {}",
+                    self.data_type()
+                ))
+            }),
+            SumOverflowMode::NullOnOverflow => Ok(current.checked_add(delta)),
+        }
+    }
+
+    /// `time` is milliseconds for [`IntervalUnit::DayTime`] (`i32`-wide, even
+    /// though `self.time` stores it widened to `i64`) or nanoseconds for
+    /// [`IntervalUnit::MonthDayNano`] (genuinely `i64`-wide). Each component
+    /// is checked against its own native width -- checking the `DayTime`
+    /// millisecond component as if it were `i64` would miss an `i32`
+    /// overflow entirely, since it'd still fit comfortably in an `i64`.
+    fn add_value(&mut self, months: i32, days: i32, time: i64) -> Result<()> {
+        let months_res = self.add_component_i32(self.months, months)?;
+        let days_res = self.add_component_i32(self.days, days)?;
+        let time_res = match self.unit {
+            IntervalUnit::DayTime => self
+                .add_component_i32(self.time as i32, time as i32)?
+                .map(i64::from),
+            IntervalUnit::MonthDayNano => self.add_component_i64(self.time, time)?,
+            IntervalUnit::YearMonth => {
+                unreachable!("YearMonth uses the generic SumAccumulator")
+            }
+        };
+        match (months_res, days_res, time_res) {
+            (Some(months), Some(days), Some(time)) => {
+                self.months = months;
+                self.days = days;
+                self.time = time;
+                self.has_value = true;
+            }
+            _ => {
+                debug_assert_eq!(self.overflow_mode, SumOverflowMode::NullOnOverflow);
+                self.months = 0;
+                self.days = 0;
+                self.time = 0;
+                self.has_value = false;
+                self.overflowed = true;
+            }
+        }
+        Ok(())
+    }
+
+    fn update_native(&mut self, values: &ArrayRef) -> Result<()> {
+        if self.overflowed {
+            return Ok(());
+        }
+        match self.unit {
+            IntervalUnit::DayTime => {
+                for v in values.as_primitive::<IntervalDayTimeType>().iter().flatten() {
+                    let (days, millis) = IntervalDayTimeType::to_parts(v);
+                    self.add_value(0, days, millis as i64)?;
+                    if self.overflowed {
+                        break;
+                    }
+                }
+            }
+            IntervalUnit::MonthDayNano => {
+                for v in values
+                    .as_primitive::<IntervalMonthDayNanoType>()
+                    .iter()
+                    .flatten()
+                {
+                    let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(v);
+                    self.add_value(months, days, nanos)?;
+                    if self.overflowed {
+                        break;
+                    }
+                }
+            }
+            IntervalUnit::YearMonth => {
+                unreachable!("YearMonth uses the generic SumAccumulator")
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Accumulator for IntervalSumAccumulator {
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        let mut state = vec![self.evaluate()?];
+        // See the comment in `SumAccumulator::state`: a sticky flag is the
+        // only way an overflow survives a merge.
+        if self.overflow_mode == SumOverflowMode::NullOnOverflow {
+            state.push(ScalarValue::Boolean(Some(self.overflowed)));
+        }
+        Ok(state)
+    }
+
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.update_native(&values[0])
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        if self.overflow_mode == SumOverflowMode::NullOnOverflow {
+            if let Some(flags) = states.get(1) {
+                if flags.as_boolean().iter().flatten().any(|f| f) {
+                    self.months = 0;
+                    self.days = 0;
+                    self.time = 0;
+                    self.has_value = false;
+                    self.overflowed = true;
+                    return Ok(());
+                }
+            }
+        }
+        self.update_native(&states[0])
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let has_value = self.has_value && !self.overflowed;
+        match self.unit {
+            IntervalUnit::DayTime => {
+                let value = has_value
+                    .then(|| IntervalDayTimeType::make_value(self.days, self.time as i32));
+                ScalarValue::new_primitive::<IntervalDayTimeType>(value, &self.data_type())
+            }
+            IntervalUnit::MonthDayNano => {
+                let value = has_value.then(|| {
+                    IntervalMonthDayNanoType::make_value(self.months, self.days, self.time)
+                });
+                ScalarValue::new_primitive::<IntervalMonthDayNanoType>(value, &self.data_type())
+            }
+            IntervalUnit::YearMonth => {
+                unreachable!("YearMonth uses the generic SumAccumulator")
+            }
+        }
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self)
+    }
+}
+
 /// This accumulator incrementally computes sums over a sliding window
 ///
 /// This is separate from [`SumAccumulator`] as requires additional state
@@ -240,6 +807,10 @@ struct SlidingSumAccumulator<T: ArrowNumericType> {
     sum: T::Native,
     count: u64,
     data_type: DataType,
+    overflow_mode: SumOverflowMode,
+    /// Once `true` (only reachable via [`SumOverflowMode::NullOnOverflow`]),
+    /// the result stays `NULL` regardless of further input.
+    overflowed: bool,
 }
 
 impl<T: ArrowNumericType> std::fmt::Debug for SlidingSumAccumulator<T> {
@@ -249,33 +820,98 @@ impl<T: ArrowNumericType> std::fmt::Debug for SlidingSumAccumulator<T> {
 }
 
 impl<T: ArrowNumericType> SlidingSumAccumulator<T> {
-    fn new(data_type: DataType) -> Self {
+    fn new(data_type: DataType, overflow_mode: SumOverflowMode) -> Self {
         Self {
             sum: T::Native::usize_as(0),
             count: 0,
             data_type,
+            overflow_mode,
+            overflowed: false,
         }
     }
 }
 
 impl<T: ArrowNumericType> Accumulator for SlidingSumAccumulator<T> {
     fn state(&mut self) -> Result<Vec<ScalarValue>> {
-        Ok(vec![self.evaluate()?, self.count.into()])
+        let mut state = vec![self.evaluate()?, self.count.into()];
+        // See the comment in `SumAccumulator::state`: a sticky flag is the
+        // only way an overflow survives a merge, since the `NULL` it turns
+        // into would otherwise just be skipped as a null when merged.
+        if self.overflow_mode == SumOverflowMode::NullOnOverflow {
+            state.push(ScalarValue::Boolean(Some(self.overflowed)));
+        }
+        Ok(state)
     }
 
     fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        if self.overflowed {
+            return Ok(());
+        }
         let values = values[0].as_primitive::<T>();
         self.count += (values.len() - values.null_count()) as u64;
-        if let Some(x) = arrow::compute::sum(values) {
-            self.sum = self.sum.add_wrapping(x)
+        match self.overflow_mode {
+            SumOverflowMode::Wrapping => {
+                if let Some(x) = arrow::compute::sum(values) {
+                    self.sum = self.sum.add_wrapping(x)
+                }
+            }
+            // Same reasoning as `SumAccumulator::update_batch`: don't let the
+            // wrapping `arrow::compute::sum` kernel hide an overflow that
+            // happens inside this batch.
+            SumOverflowMode::Checked | SumOverflowMode::NullOnOverflow => {
+                for x in values.iter().flatten() {
+                    match add_with_overflow_mode::<T>(
+                        self.sum,
+                        x,
+                        &self.data_type,
+                        self.overflow_mode,
+                    )? {
+                        Some(v) => self.sum = v,
+                        None => {
+                            self.overflowed = true;
+                            break;
+                        }
+                    }
+                }
+            }
         }
         Ok(())
     }
 
     fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        if self.overflow_mode == SumOverflowMode::NullOnOverflow {
+            if let Some(flags) = states.get(2) {
+                if flags.as_boolean().iter().flatten().any(|f| f) {
+                    self.overflowed = true;
+                }
+            }
+        }
+        if self.overflowed {
+            return Ok(());
+        }
         let values = states[0].as_primitive::<T>();
-        if let Some(x) = arrow::compute::sum(values) {
-            self.sum = self.sum.add_wrapping(x)
+        match self.overflow_mode {
+            SumOverflowMode::Wrapping => {
+                if let Some(x) = arrow::compute::sum(values) {
+                    self.sum = self.sum.add_wrapping(x)
+                }
+            }
+            SumOverflowMode::Checked | SumOverflowMode::NullOnOverflow => {
+                for x in values.iter().flatten() {
+                    match add_with_overflow_mode::<T>(
+                        self.sum,
+                        x,
+                        &self.data_type,
+                        self.overflow_mode,
+                    )? {
+                        Some(v) => self.sum = v,
+                        None => {
+                            self.overflowed = true;
+                            break;
+                        }
+                    }
+                }
+            }
         }
         if let Some(x) = arrow::compute::sum(states[1].as_primitive::<UInt64Type>()) {
             self.count += x;
@@ -284,7 +920,7 @@ impl<T: ArrowNumericType> Accumulator for SlidingSumAccumulator<T> {
     }
 
     fn evaluate(&mut self) -> Result<ScalarValue> {
-        let v = (self.count != 0).then_some(self.sum);
+        let v = (self.count != 0 && !self.overflowed).then_some(self.sum);
         ScalarValue::new_primitive::<T>(v, &self.data_type)
     }
 
@@ -305,3 +941,510 @@ impl<T: ArrowNumericType> Accumulator for SlidingSumAccumulator<T> {
         true
     }
 }
+
+/// Wraps a native value so it can be used as a key in a [`HashSet`].
+///
+/// `T::Native` does not implement `Eq`/`Hash` (floats in particular), so we
+/// hash and compare the raw little-endian bytes of the value instead. This
+/// is the same trick used throughout the aggregate/grouping code for
+/// building distinct sets over arbitrary native types.
+#[derive(Debug, Clone, Copy)]
+struct Hashable<T>(T);
+
+impl<T: ArrowNativeType> PartialEq for Hashable<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_le_bytes().as_ref() == other.0.to_le_bytes().as_ref()
+    }
+}
+
+impl<T: ArrowNativeType> Eq for Hashable<T> {}
+
+impl<T: ArrowNativeType> std::hash::Hash for Hashable<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.to_le_bytes().as_ref().hash(state)
+    }
+}
+
+/// This accumulator computes SUM DISTINCT, i.e. it only sums each distinct
+/// non-null value once.
+///
+/// The running set of distinct values is carried in `state`/`merge_batch` as
+/// a `List` array so that partial aggregates computed on different
+/// partitions can be merged correctly before the final sum is taken.
+struct DistinctSumAccumulator<T: ArrowNumericType> {
+    values: HashSet<Hashable<T::Native>, RandomState>,
+    data_type: DataType,
+}
+
+impl<T: ArrowNumericType> std::fmt::Debug for DistinctSumAccumulator<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DistinctSumAccumulator({})", self.data_type)
+    }
+}
+
+impl<T: ArrowNumericType> DistinctSumAccumulator<T> {
+    fn try_new(data_type: &DataType) -> Result<Self> {
+        Ok(Self {
+            values: HashSet::default(),
+            data_type: data_type.clone(),
+        })
+    }
+}
+
+impl<T: ArrowNumericType> Accumulator for DistinctSumAccumulator<T> {
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        let values = self
+            .values
+            .iter()
+            .map(|v| ScalarValue::new_primitive::<T>(Some(v.0), &self.data_type))
+            .collect::<Result<Vec<_>>>()?;
+        let arr = ScalarValue::new_list(&values, &self.data_type);
+        Ok(vec![ScalarValue::List(arr)])
+    }
+
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let values = values[0].as_primitive::<T>();
+        self.values
+            .extend(values.iter().flatten().map(Hashable));
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        let array = states[0].as_list::<i32>();
+        for v in array.iter().flatten() {
+            let v = v.as_primitive::<T>();
+            self.values.extend(v.iter().flatten().map(Hashable));
+        }
+        Ok(())
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let mut sum = None;
+        for v in self.values.iter() {
+            let s = sum.get_or_insert(T::Native::usize_as(0));
+            *s = s.add_wrapping(v.0);
+        }
+        ScalarValue::new_primitive::<T>(sum, &self.data_type)
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self)
+            + self.values.capacity() * std::mem::size_of::<Hashable<T::Native>>()
+    }
+}
+
+/// Vectorized hash-grouping accumulator for `SUM DISTINCT`.
+///
+/// Unlike [`DistinctSumAccumulator`], which tracks a single set for the
+/// whole input, this keeps one set per group so a full vectorized
+/// [`GroupsAccumulator`] pass can service many groups at once instead of
+/// falling back to the slow per-group [`Accumulator`] path.
+struct DistinctSumGroupsAccumulator<T: ArrowNumericType> {
+    values: Vec<HashSet<Hashable<T::Native>, RandomState>>,
+    data_type: DataType,
+}
+
+impl<T: ArrowNumericType> std::fmt::Debug for DistinctSumGroupsAccumulator<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DistinctSumGroupsAccumulator({})", self.data_type)
+    }
+}
+
+impl<T: ArrowNumericType> DistinctSumGroupsAccumulator<T> {
+    fn new(data_type: &DataType) -> Self {
+        Self {
+            values: vec![],
+            data_type: data_type.clone(),
+        }
+    }
+
+    fn ensure_groups(&mut self, total_num_groups: usize) {
+        if self.values.len() < total_num_groups {
+            self.values
+                .resize_with(total_num_groups, HashSet::default);
+        }
+    }
+}
+
+impl<T: ArrowNumericType> GroupsAccumulator for DistinctSumGroupsAccumulator<T> {
+    fn update_batch(
+        &mut self,
+        values: &[ArrayRef],
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+        total_num_groups: usize,
+    ) -> Result<()> {
+        self.ensure_groups(total_num_groups);
+
+        let values = values[0].as_primitive::<T>();
+        for (row, &group_index) in group_indices.iter().enumerate() {
+            if values.is_null(row) {
+                continue;
+            }
+            if opt_filter.is_some_and(|filter| !filter.value(row)) {
+                continue;
+            }
+            self.values[group_index].insert(Hashable(values.value(row)));
+        }
+        Ok(())
+    }
+
+    fn merge_batch(
+        &mut self,
+        values: &[ArrayRef],
+        group_indices: &[usize],
+        opt_filter: Option<&BooleanArray>,
+        total_num_groups: usize,
+    ) -> Result<()> {
+        self.ensure_groups(total_num_groups);
+
+        let lists = values[0].as_list::<i32>();
+        for (row, &group_index) in group_indices.iter().enumerate() {
+            if lists.is_null(row) {
+                continue;
+            }
+            if opt_filter.is_some_and(|filter| !filter.value(row)) {
+                continue;
+            }
+            let elements = lists.value(row);
+            let elements = elements.as_primitive::<T>();
+            self.values[group_index].extend(elements.iter().flatten().map(Hashable));
+        }
+        Ok(())
+    }
+
+    fn state(&mut self, emit_to: EmitTo) -> Result<Vec<ArrayRef>> {
+        let sets = emit_to.take_needed(&mut self.values);
+        let list_scalars = sets
+            .iter()
+            .map(|set| {
+                let values = set
+                    .iter()
+                    .map(|v| ScalarValue::new_primitive::<T>(Some(v.0), &self.data_type))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(ScalarValue::List(ScalarValue::new_list(
+                    &values,
+                    &self.data_type,
+                )))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(vec![ScalarValue::iter_to_array(list_scalars)?])
+    }
+
+    fn evaluate(&mut self, emit_to: EmitTo) -> Result<ArrayRef> {
+        let sets = emit_to.take_needed(&mut self.values);
+        let sums = sets
+            .iter()
+            .map(|set| {
+                let mut sum = None;
+                for v in set.iter() {
+                    let s = sum.get_or_insert(T::Native::usize_as(0));
+                    *s = s.add_wrapping(v.0);
+                }
+                sum
+            })
+            .collect::<PrimitiveArray<T>>();
+        Ok(Arc::new(sums.with_data_type(self.data_type.clone())))
+    }
+
+    fn size(&self) -> usize {
+        self.values
+            .iter()
+            .map(|s| s.capacity() * std::mem::size_of::<Hashable<T::Native>>())
+            .sum::<usize>()
+            + std::mem::size_of_val(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Int64Array;
+
+    fn batch(values: Vec<Option<i64>>) -> ArrayRef {
+        Arc::new(Int64Array::from(values))
+    }
+
+    #[test]
+    fn distinct_sum_dedups_within_and_across_batches() {
+        let mut acc = DistinctSumAccumulator::<Int64Type>::try_new(&DataType::Int64).unwrap();
+        acc.update_batch(&[batch(vec![Some(1), Some(2), Some(1), None])])
+            .unwrap();
+        acc.update_batch(&[batch(vec![Some(2), Some(3)])]).unwrap();
+
+        // 1 + 2 + 3, each counted once despite repeating across both batches
+        assert_eq!(acc.evaluate().unwrap(), ScalarValue::Int64(Some(6)));
+    }
+
+    #[test]
+    fn distinct_sum_state_round_trips_through_merge() {
+        let mut partition_a =
+            DistinctSumAccumulator::<Int64Type>::try_new(&DataType::Int64).unwrap();
+        partition_a
+            .update_batch(&[batch(vec![Some(1), Some(2)])])
+            .unwrap();
+        let state_a = partition_a.state().unwrap();
+
+        let mut partition_b =
+            DistinctSumAccumulator::<Int64Type>::try_new(&DataType::Int64).unwrap();
+        // Overlaps with partition_a's `2`, which must not be double-counted.
+        partition_b
+            .update_batch(&[batch(vec![Some(2), Some(3)])])
+            .unwrap();
+        let state_b = partition_b.state().unwrap();
+
+        let mut merged =
+            DistinctSumAccumulator::<Int64Type>::try_new(&DataType::Int64).unwrap();
+        let ScalarValue::List(list_a) = &state_a[0] else {
+            panic!("expected a list state")
+        };
+        merged.merge_batch(&[list_a.clone() as ArrayRef]).unwrap();
+        let ScalarValue::List(list_b) = &state_b[0] else {
+            panic!("expected a list state")
+        };
+        merged.merge_batch(&[list_b.clone() as ArrayRef]).unwrap();
+
+        assert_eq!(merged.evaluate().unwrap(), ScalarValue::Int64(Some(6)));
+    }
+
+    #[test]
+    fn sum_checked_catches_overflow_within_a_single_batch() {
+        // `arrow::compute::sum` would wrap `[i64::MAX, i64::MAX]` to `-2`
+        // internally; Checked must still catch it.
+        let mut acc =
+            SumAccumulator::<Int64Type>::new(DataType::Int64, SumOverflowMode::Checked);
+        let err = acc
+            .update_batch(&[batch(vec![Some(i64::MAX), Some(i64::MAX)])])
+            .unwrap_err();
+        assert!(err.to_string().contains("overflow"), "{err}");
+    }
+
+    #[test]
+    fn sum_wrapping_still_wraps_within_a_batch() {
+        let mut acc =
+            SumAccumulator::<Int64Type>::new(DataType::Int64, SumOverflowMode::Wrapping);
+        acc.update_batch(&[batch(vec![Some(i64::MAX), Some(1)])])
+            .unwrap();
+        assert_eq!(acc.evaluate().unwrap(), ScalarValue::Int64(Some(i64::MIN)));
+    }
+
+    #[test]
+    fn sum_null_on_overflow_nulls_out_within_a_single_batch() {
+        let mut acc = SumAccumulator::<Int64Type>::new(
+            DataType::Int64,
+            SumOverflowMode::NullOnOverflow,
+        );
+        acc.update_batch(&[batch(vec![Some(i64::MAX), Some(i64::MAX)])])
+            .unwrap();
+        assert_eq!(acc.evaluate().unwrap(), ScalarValue::Int64(None));
+
+        let state = acc.state().unwrap();
+        assert_eq!(state[0], ScalarValue::Int64(None));
+        assert_eq!(state[1], ScalarValue::Boolean(Some(true)));
+    }
+
+    #[test]
+    fn sum_null_on_overflow_sticks_across_merge() {
+        // One partition overflowed...
+        let overflowed_sum: ArrayRef = Arc::new(Int64Array::from(vec![None]));
+        let overflowed_flag: ArrayRef = Arc::new(BooleanArray::from(vec![true]));
+
+        // ...while another computed a perfectly ordinary partial sum. Merging
+        // the ordinary partial in afterwards must not erase the overflow --
+        // `merge_batch` would otherwise just skip the `NULL` partial.
+        let ok_sum: ArrayRef = Arc::new(Int64Array::from(vec![Some(5)]));
+        let ok_flag: ArrayRef = Arc::new(BooleanArray::from(vec![false]));
+
+        let mut acc = SumAccumulator::<Int64Type>::new(
+            DataType::Int64,
+            SumOverflowMode::NullOnOverflow,
+        );
+        acc.merge_batch(&[overflowed_sum, overflowed_flag]).unwrap();
+        acc.merge_batch(&[ok_sum, ok_flag]).unwrap();
+
+        assert_eq!(acc.evaluate().unwrap(), ScalarValue::Int64(None));
+    }
+
+    #[test]
+    fn sum_udaf_with_overflow_mode_is_reachable_from_a_registered_udaf() {
+        // `sum_udaf()` itself always wraps; a planner wanting Checked/
+        // NullOnOverflow semantics needs a real way to get an `AggregateUDF`
+        // carrying that mode, not just the private `overflow_mode` field on
+        // `Sum` that nothing outside this module could reach before.
+        let checked = sum_udaf_with_overflow_mode(SumOverflowMode::Checked);
+        let checked = checked
+            .inner()
+            .as_any()
+            .downcast_ref::<Sum>()
+            .expect("sum_udaf_with_overflow_mode should build a Sum");
+        assert_eq!(checked.overflow_mode(), SumOverflowMode::Checked);
+
+        // The default registry entry is unaffected, so existing plans keep
+        // wrapping behavior.
+        assert_eq!(Sum::default().overflow_mode(), SumOverflowMode::Wrapping);
+    }
+
+    #[test]
+    fn sum_interval_day_time_adds_components_independently() {
+        // `i32::MAX + 1` wraps within the millisecond component while the
+        // day components add up on their own.
+        let v1 = IntervalDayTimeType::make_value(1, i32::MAX);
+        let v2 = IntervalDayTimeType::make_value(2, 1);
+        let values: ArrayRef =
+            Arc::new(PrimitiveArray::<IntervalDayTimeType>::from(vec![v1, v2]));
+
+        let mut acc =
+            IntervalSumAccumulator::new(IntervalUnit::DayTime, SumOverflowMode::Wrapping);
+        acc.update_batch(&[values]).unwrap();
+
+        let ScalarValue::IntervalDayTime(Some(sum)) = acc.evaluate().unwrap() else {
+            panic!("expected an interval scalar")
+        };
+        let (days, millis) = IntervalDayTimeType::to_parts(sum);
+        assert_eq!(days, 3);
+        assert_eq!(millis, i32::MAX.wrapping_add(1));
+    }
+
+    #[test]
+    fn sum_interval_month_day_nano_adds_components_independently() {
+        // Same idea, one level deeper: `i64::MAX + 1` wraps within the
+        // nanosecond component while months and days add up on their own.
+        let v1 = IntervalMonthDayNanoType::make_value(1, 2, i64::MAX);
+        let v2 = IntervalMonthDayNanoType::make_value(3, 4, 1);
+        let values: ArrayRef =
+            Arc::new(PrimitiveArray::<IntervalMonthDayNanoType>::from(vec![v1, v2]));
+
+        let mut acc = IntervalSumAccumulator::new(
+            IntervalUnit::MonthDayNano,
+            SumOverflowMode::Wrapping,
+        );
+        acc.update_batch(&[values]).unwrap();
+
+        let ScalarValue::IntervalMonthDayNano(Some(sum)) = acc.evaluate().unwrap() else {
+            panic!("expected an interval scalar")
+        };
+        let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(sum);
+        assert_eq!(months, 4);
+        assert_eq!(days, 6);
+        assert_eq!(nanos, i64::MAX.wrapping_add(1));
+    }
+
+    #[test]
+    fn sum_interval_checked_mode_catches_component_overflow() {
+        let v1 = IntervalMonthDayNanoType::make_value(i32::MAX, 0, 0);
+        let v2 = IntervalMonthDayNanoType::make_value(1, 0, 0);
+        let values: ArrayRef =
+            Arc::new(PrimitiveArray::<IntervalMonthDayNanoType>::from(vec![v1, v2]));
+
+        let mut acc = IntervalSumAccumulator::new(
+            IntervalUnit::MonthDayNano,
+            SumOverflowMode::Checked,
+        );
+        let err = acc.update_batch(&[values]).unwrap_err();
+        assert!(err.to_string().contains("overflow"), "{err}");
+    }
+
+    #[test]
+    fn sum_interval_day_time_checked_mode_catches_millisecond_component_overflow() {
+        // The millisecond component is only `i32`-wide even though it's
+        // stored in an `i64` field -- `i32::MAX + 1` must still be caught,
+        // not treated as in-range because it fits comfortably in an `i64`.
+        let v1 = IntervalDayTimeType::make_value(0, i32::MAX);
+        let v2 = IntervalDayTimeType::make_value(0, 1);
+        let values: ArrayRef =
+            Arc::new(PrimitiveArray::<IntervalDayTimeType>::from(vec![v1, v2]));
+
+        let mut acc =
+            IntervalSumAccumulator::new(IntervalUnit::DayTime, SumOverflowMode::Checked);
+        let err = acc.update_batch(&[values]).unwrap_err();
+        assert!(err.to_string().contains("overflow"), "{err}");
+    }
+
+    #[test]
+    fn sum_interval_null_on_overflow_sticks_across_merge() {
+        let overflowed_value: ArrayRef =
+            Arc::new(PrimitiveArray::<IntervalDayTimeType>::from(vec![None]));
+        let overflowed_flag: ArrayRef = Arc::new(BooleanArray::from(vec![true]));
+
+        let ok_value: ArrayRef = Arc::new(PrimitiveArray::<IntervalDayTimeType>::from(vec![
+            Some(IntervalDayTimeType::make_value(1, 0)),
+        ]));
+        let ok_flag: ArrayRef = Arc::new(BooleanArray::from(vec![false]));
+
+        let mut acc = IntervalSumAccumulator::new(
+            IntervalUnit::DayTime,
+            SumOverflowMode::NullOnOverflow,
+        );
+        acc.merge_batch(&[overflowed_value, overflowed_flag])
+            .unwrap();
+        acc.merge_batch(&[ok_value, ok_flag]).unwrap();
+
+        assert_eq!(acc.evaluate().unwrap(), ScalarValue::IntervalDayTime(None));
+    }
+
+    #[test]
+    fn sum_duration_accumulates_like_plain_integers() {
+        // Durations aren't packed the way intervals are, so the generic
+        // `SumAccumulator` path (no component-bleed risk) is correct as-is.
+        let values: ArrayRef = Arc::new(PrimitiveArray::<DurationSecondType>::from(vec![
+            Some(30),
+            Some(12),
+        ]));
+        let mut acc = SumAccumulator::<DurationSecondType>::new(
+            DataType::Duration(TimeUnit::Second),
+            SumOverflowMode::Wrapping,
+        );
+        acc.update_batch(&[values]).unwrap();
+        assert_eq!(
+            acc.evaluate().unwrap(),
+            ScalarValue::DurationSecond(Some(42))
+        );
+    }
+
+    #[test]
+    fn distinct_sum_groups_accumulator_state_round_trips_through_merge_and_evaluate() {
+        let mut acc = DistinctSumGroupsAccumulator::<Int64Type>::new(&DataType::Int64);
+        // Two groups; group 1 sees a repeated `2` that must be deduped.
+        acc.update_batch(
+            &[batch(vec![Some(1), Some(2), Some(2), Some(5)])],
+            &[0, 1, 1, 1],
+            None,
+            2,
+        )
+        .unwrap();
+
+        let state = acc.state(EmitTo::All).unwrap();
+        assert_eq!(state[0].len(), 2);
+
+        let mut merged = DistinctSumGroupsAccumulator::<Int64Type>::new(&DataType::Int64);
+        merged.merge_batch(&state, &[0, 1], None, 2).unwrap();
+
+        let evaluated = merged.evaluate(EmitTo::All).unwrap();
+        let evaluated = evaluated.as_primitive::<Int64Type>();
+        assert_eq!(evaluated.value(0), 1);
+        // 2 + 5, with the repeated `2` counted once thanks to dedup.
+        assert_eq!(evaluated.value(1), 7);
+    }
+
+    #[test]
+    fn reject_distinct_packed_interval_rejects_day_time_and_month_day_nano() {
+        assert!(
+            reject_distinct_packed_interval(&DataType::Interval(IntervalUnit::DayTime))
+                .is_err()
+        );
+        assert!(reject_distinct_packed_interval(&DataType::Interval(
+            IntervalUnit::MonthDayNano
+        ))
+        .is_err());
+
+        // Unpacked types are unaffected.
+        assert!(reject_distinct_packed_interval(&DataType::Interval(
+            IntervalUnit::YearMonth
+        ))
+        .is_ok());
+        assert!(
+            reject_distinct_packed_interval(&DataType::Duration(TimeUnit::Second)).is_ok()
+        );
+        assert!(reject_distinct_packed_interval(&DataType::Int64).is_ok());
+    }
+}